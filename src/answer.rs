@@ -0,0 +1,365 @@
+use crate::{FetchError, Result};
+use log::info;
+use reqwest::header::{CONTENT_TYPE, COOKIE};
+use std::fs;
+use std::path::Path;
+use std::time::Duration;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum AnswerResult {
+    Correct,
+    Incorrect,
+    TooHigh,
+    TooLow,
+    AlreadyComplete,
+    RateLimited { wait: Duration },
+}
+
+fn escape_json_string(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+
+    for c in value.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            _ => escaped.push(c),
+        }
+    }
+
+    escaped
+}
+
+fn parse_json_string(rest: &str) -> Option<(String, &str)> {
+    let mut value = String::new();
+    let mut chars = rest.char_indices();
+
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '\\' => {
+                let (_, escaped) = chars.next()?;
+                value.push(escaped);
+            }
+            '"' => return Some((value, &rest[i + 1..])),
+            _ => value.push(c),
+        }
+    }
+
+    None
+}
+
+struct AnswerCache {
+    path: String,
+    correct: Option<String>,
+    wrong: Vec<String>,
+}
+
+impl AnswerCache {
+    fn load(day: &str, year: &str, part: u8) -> AnswerCache {
+        let path = Self::get_cache_filename(day, year, part);
+
+        let (correct, wrong) = fs::read_to_string(&path)
+            .ok()
+            .map(|raw| Self::parse(&raw))
+            .unwrap_or((None, Vec::new()));
+
+        AnswerCache { path, correct, wrong }
+    }
+
+    fn get_cache_filename(day: &str, year: &str, part: u8) -> String {
+        format!("{}/{}-{}-{}.answers.json", crate::INPUT_DIR, year, day, part)
+    }
+
+    fn parse(raw: &str) -> (Option<String>, Vec<String>) {
+        let correct = Self::extract_field(raw, "correct");
+        let wrong = Self::extract_array(raw, "wrong");
+
+        (correct, wrong)
+    }
+
+    fn extract_field(raw: &str, key: &str) -> Option<String> {
+        let marker = format!("\"{}\":", key);
+        let start = raw.find(&marker)? + marker.len();
+        let rest = raw[start..].trim_start();
+
+        if rest.starts_with("null") {
+            return None;
+        }
+
+        let rest = rest.strip_prefix('"')?;
+        let (value, _) = parse_json_string(rest)?;
+
+        Some(value)
+    }
+
+    fn extract_array(raw: &str, key: &str) -> Vec<String> {
+        let marker = format!("\"{}\":", key);
+        let start = match raw.find(&marker) {
+            Some(pos) => pos + marker.len(),
+            None => return Vec::new(),
+        };
+
+        let mut rest = match raw[start..].trim_start().strip_prefix('[') {
+            Some(rest) => rest,
+            None => return Vec::new(),
+        };
+
+        let mut values = Vec::new();
+
+        loop {
+            rest = rest.trim_start();
+
+            if rest.starts_with(']') || rest.is_empty() {
+                break;
+            }
+
+            rest = match rest.strip_prefix('"') {
+                Some(rest) => rest,
+                None => break,
+            };
+
+            let (value, remainder) = match parse_json_string(rest) {
+                Some(pair) => pair,
+                None => break,
+            };
+
+            values.push(value);
+            let trimmed = remainder.trim_start();
+            rest = trimmed.strip_prefix(',').unwrap_or(trimmed);
+        }
+
+        values
+    }
+
+    fn is_known_wrong(&self, answer: &str) -> bool {
+        self.wrong.iter().any(|w| w == answer)
+    }
+
+    fn remember_wrong(&mut self, answer: &str) {
+        if !self.is_known_wrong(answer) {
+            self.wrong.push(answer.to_string());
+        }
+    }
+
+    fn remember_correct(&mut self, answer: &str) {
+        self.correct = Some(answer.to_string());
+    }
+
+    fn save(&self) -> Result<()> {
+        if !Path::new(crate::INPUT_DIR).exists() {
+            fs::create_dir(crate::INPUT_DIR)?;
+        }
+
+        let correct = match &self.correct {
+            Some(value) => format!("\"{}\"", escape_json_string(value)),
+            None => "null".to_string(),
+        };
+        let wrong = self
+            .wrong
+            .iter()
+            .map(|w| format!("\"{}\"", escape_json_string(w)))
+            .collect::<Vec<_>>()
+            .join(",");
+
+        let json = format!("{{\"correct\":{},\"wrong\":[{}]}}", correct, wrong);
+
+        fs::write(&self.path, json)?;
+
+        Ok(())
+    }
+}
+
+pub fn submit_answer(day: &str, year: &str, part: u8, answer: &str, session: &str) -> Result<AnswerResult> {
+    info!("Submitting answer for day {}-{} part {}", day, year, part);
+
+    let mut cache = AnswerCache::load(day, year, part);
+
+    if cache.is_known_wrong(answer) {
+        info!("Answer already known to be wrong, skipping submission");
+        return Ok(AnswerResult::Incorrect);
+    }
+
+    if let Some(correct) = &cache.correct {
+        if correct == answer {
+            return Ok(AnswerResult::Correct);
+        }
+    }
+
+    crate::throttle::wait_if_needed();
+
+    let url = format!("https://adventofcode.com/{}/day/{}/answer", year, day);
+    let body = format!("level={}&answer={}", part, answer);
+
+    let response = crate::client::build_client()
+        .post(&url)
+        .header(COOKIE, format!("session={}", session))
+        .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
+        .body(body)
+        .send()?;
+
+    let response = match response.error_for_status() {
+        Ok(response) => response,
+        Err(e) => return Err(crate::client::classify_status_error(day, year, e)),
+    };
+
+    let response = response.text()?;
+    let result = parse_answer_result(&response)?;
+
+    match &result {
+        AnswerResult::Correct => cache.remember_correct(answer),
+        AnswerResult::Incorrect | AnswerResult::TooHigh | AnswerResult::TooLow => {
+            cache.remember_wrong(answer)
+        }
+        AnswerResult::AlreadyComplete | AnswerResult::RateLimited { .. } => {}
+    }
+
+    cache.save()?;
+
+    Ok(result)
+}
+
+fn parse_answer_result(response: &str) -> Result<AnswerResult> {
+    if response.contains("That's the right answer") {
+        return Ok(AnswerResult::Correct);
+    }
+
+    if response.contains("You gave an answer too recently") {
+        return Ok(AnswerResult::RateLimited {
+            wait: parse_wait_duration(response),
+        });
+    }
+
+    if response.contains("Did you already complete it") {
+        return Ok(AnswerResult::AlreadyComplete);
+    }
+
+    if response.contains("that's not the right answer") {
+        if response.contains("too high") {
+            return Ok(AnswerResult::TooHigh);
+        }
+
+        if response.contains("too low") {
+            return Ok(AnswerResult::TooLow);
+        }
+
+        return Ok(AnswerResult::Incorrect);
+    }
+
+    Err(FetchError::Parse {
+        value: response.to_string(),
+    })
+}
+
+fn parse_wait_duration(response: &str) -> Duration {
+    let marker = "You have ";
+
+    let wait = response.find(marker).and_then(|start| {
+        let rest = &response[start + marker.len()..];
+        let end = rest.find(" seconds left to wait")?;
+
+        rest[..end].parse::<u64>().ok()
+    });
+
+    Duration::from_secs(wait.unwrap_or(60))
+}
+
+#[cfg(test)]
+mod answer_cache_parsing_tests {
+    use super::AnswerCache;
+
+    #[test]
+    fn extracts_correct_and_wrong_answers() {
+        let raw = r#"{"correct":"42","wrong":["1","2"]}"#;
+        let (correct, wrong) = AnswerCache::parse(raw);
+
+        assert_eq!(Some("42".to_string()), correct);
+        assert_eq!(vec!["1".to_string(), "2".to_string()], wrong);
+    }
+
+    #[test]
+    fn handles_null_correct_and_empty_wrong() {
+        let raw = r#"{"correct":null,"wrong":[]}"#;
+        let (correct, wrong) = AnswerCache::parse(raw);
+
+        assert_eq!(None, correct);
+        assert!(wrong.is_empty());
+    }
+
+    #[test]
+    fn round_trips_values_with_commas_and_quotes() {
+        let correct = super::escape_json_string(r#"a,weird"answer"#);
+        let wrong_one = super::escape_json_string("1,2");
+        let wrong_two = super::escape_json_string(r#"say "hi""#);
+        let raw = format!(
+            r#"{{"correct":"{}","wrong":["{}","{}"]}}"#,
+            correct, wrong_one, wrong_two
+        );
+
+        let (parsed_correct, parsed_wrong) = AnswerCache::parse(&raw);
+
+        assert_eq!(Some(r#"a,weird"answer"#.to_string()), parsed_correct);
+        assert_eq!(
+            vec!["1,2".to_string(), r#"say "hi""#.to_string()],
+            parsed_wrong
+        );
+    }
+}
+
+#[cfg(test)]
+mod parse_answer_result_tests {
+    use super::{parse_answer_result, AnswerResult};
+    use std::time::Duration;
+
+    #[test]
+    fn recognizes_correct_answer() {
+        let result = parse_answer_result("That's the right answer!");
+
+        assert_eq!(AnswerResult::Correct, result.unwrap());
+    }
+
+    #[test]
+    fn recognizes_too_high() {
+        let result = parse_answer_result("that's not the right answer; your answer is too high.");
+
+        assert_eq!(AnswerResult::TooHigh, result.unwrap());
+    }
+
+    #[test]
+    fn recognizes_too_low() {
+        let result = parse_answer_result("that's not the right answer; your answer is too low.");
+
+        assert_eq!(AnswerResult::TooLow, result.unwrap());
+    }
+
+    #[test]
+    fn recognizes_incorrect_without_direction_hint() {
+        let result = parse_answer_result("that's not the right answer.");
+
+        assert_eq!(AnswerResult::Incorrect, result.unwrap());
+    }
+
+    #[test]
+    fn recognizes_already_complete() {
+        let result = parse_answer_result("Did you already complete it?");
+
+        assert_eq!(AnswerResult::AlreadyComplete, result.unwrap());
+    }
+
+    #[test]
+    fn recognizes_rate_limited_with_wait() {
+        let result = parse_answer_result("You gave an answer too recently. You have 42 seconds left to wait.");
+
+        assert_eq!(
+            AnswerResult::RateLimited {
+                wait: Duration::from_secs(42)
+            },
+            result.unwrap()
+        );
+    }
+
+    #[test]
+    fn fails_on_unrecognized_response() {
+        let result = parse_answer_result("something completely unexpected");
+
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,79 @@
+use crate::FetchError;
+use reqwest::blocking::Client;
+use reqwest::redirect::Policy;
+use std::sync::OnceLock;
+
+static CLIENT: OnceLock<Client> = OnceLock::new();
+
+pub(crate) fn classify_status_error(day: &str, year: &str, error: reqwest::Error) -> FetchError {
+    match classify_status(error.status(), day, year) {
+        Some(classified) => classified,
+        None => FetchError::Http(error),
+    }
+}
+
+fn classify_status(
+    status: Option<reqwest::StatusCode>,
+    day: &str,
+    year: &str,
+) -> Option<FetchError> {
+    match status {
+        Some(reqwest::StatusCode::NOT_FOUND) => Some(FetchError::NotLiveYet {
+            day: day.to_string(),
+            year: year.to_string(),
+        }),
+        Some(reqwest::StatusCode::SERVICE_UNAVAILABLE) => Some(FetchError::ServiceUnavailable),
+        _ => None,
+    }
+}
+
+pub(crate) fn build_client() -> &'static Client {
+    CLIENT.get_or_init(|| {
+        let user_agent = format!(
+            "{}/{} (https://github.com/hjaremko/aoc-fetch)",
+            env!("CARGO_PKG_NAME"),
+            env!("CARGO_PKG_VERSION")
+        );
+
+        Client::builder()
+            .redirect(Policy::none())
+            .user_agent(user_agent)
+            .build()
+            .expect("Unable to build the HTTP client")
+    })
+}
+
+#[cfg(test)]
+mod classify_status_tests {
+    use super::classify_status;
+    use crate::FetchError;
+
+    #[test]
+    fn not_found_is_not_live_yet() {
+        let result = classify_status(Some(reqwest::StatusCode::NOT_FOUND), "1", "2020");
+
+        assert!(matches!(
+            result,
+            Some(FetchError::NotLiveYet { day, year }) if day == "1" && year == "2020"
+        ));
+    }
+
+    #[test]
+    fn service_unavailable_is_service_unavailable() {
+        let result = classify_status(Some(reqwest::StatusCode::SERVICE_UNAVAILABLE), "1", "2020");
+
+        assert!(matches!(result, Some(FetchError::ServiceUnavailable)));
+    }
+
+    #[test]
+    fn other_status_is_not_classified() {
+        let result = classify_status(Some(reqwest::StatusCode::FORBIDDEN), "1", "2020");
+
+        assert!(result.is_none());
+    }
+
+    #[test]
+    fn missing_status_is_not_classified() {
+        assert!(classify_status(None, "1", "2020").is_none());
+    }
+}
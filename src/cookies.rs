@@ -0,0 +1,148 @@
+use crate::{FetchError, Result};
+use std::fs;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+const COOKIES_FILE: &str = "cookies.txt";
+const COOKIE_DOMAIN: &str = "adventofcode.com";
+const COOKIE_NAME: &str = "session";
+
+pub(crate) fn load_session_from_cookies_file() -> Result<String> {
+    let raw = fs::read_to_string(COOKIES_FILE)?;
+    let now = current_epoch();
+
+    raw.lines()
+        .filter_map(parse_cookie_line)
+        .find(|cookie| cookie.name == COOKIE_NAME && cookie.matches_domain(COOKIE_DOMAIN) && !cookie.is_expired(now))
+        .map(|cookie| cookie.value)
+        .ok_or(FetchError::InvalidSession)
+}
+
+struct NetscapeCookie {
+    domain: String,
+    expires: u64,
+    name: String,
+    value: String,
+}
+
+impl NetscapeCookie {
+    fn matches_domain(&self, domain: &str) -> bool {
+        self.domain.trim_start_matches('.') == domain
+    }
+
+    fn is_expired(&self, now: u64) -> bool {
+        self.expires != 0 && self.expires < now
+    }
+}
+
+fn parse_cookie_line(line: &str) -> Option<NetscapeCookie> {
+    if line.trim().is_empty() {
+        return None;
+    }
+
+    // Browser exports mark HttpOnly cookies (AoC's `session` among them) with a
+    // `#HttpOnly_` prefix instead of a plain comment `#` line.
+    let line = match line.strip_prefix("#HttpOnly_") {
+        Some(rest) => rest,
+        None if line.starts_with('#') => return None,
+        None => line,
+    };
+
+    let fields: Vec<&str> = line.split('\t').collect();
+
+    if fields.len() != 7 {
+        return None;
+    }
+
+    Some(NetscapeCookie {
+        domain: fields[0].to_string(),
+        expires: fields[4].parse().unwrap_or(0),
+        name: fields[5].to_string(),
+        value: fields[6].to_string(),
+    })
+}
+
+fn current_epoch() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs()
+}
+
+#[cfg(test)]
+mod parse_cookie_line_tests {
+    use super::parse_cookie_line;
+
+    #[test]
+    fn parses_plain_line() {
+        let line = "adventofcode.com\tFALSE\t/\tTRUE\t0\tsession\tabc123";
+        let cookie = parse_cookie_line(line).unwrap();
+
+        assert_eq!("adventofcode.com", cookie.domain);
+        assert_eq!(0, cookie.expires);
+        assert_eq!("session", cookie.name);
+        assert_eq!("abc123", cookie.value);
+    }
+
+    #[test]
+    fn parses_http_only_line() {
+        let line = "#HttpOnly_.adventofcode.com\tTRUE\t/\tTRUE\t1999999999\tsession\tabc123";
+        let cookie = parse_cookie_line(line).unwrap();
+
+        assert_eq!(".adventofcode.com", cookie.domain);
+        assert_eq!("session", cookie.name);
+        assert_eq!("abc123", cookie.value);
+    }
+
+    #[test]
+    fn skips_plain_comment_line() {
+        let line = "# Netscape HTTP Cookie File";
+
+        assert!(parse_cookie_line(line).is_none());
+    }
+
+    #[test]
+    fn skips_malformed_line() {
+        let line = "adventofcode.com\tFALSE\t/";
+
+        assert!(parse_cookie_line(line).is_none());
+    }
+}
+
+#[cfg(test)]
+mod netscape_cookie_tests {
+    use super::NetscapeCookie;
+
+    fn cookie(domain: &str, expires: u64) -> NetscapeCookie {
+        NetscapeCookie {
+            domain: domain.to_string(),
+            expires,
+            name: "session".to_string(),
+            value: "abc123".to_string(),
+        }
+    }
+
+    #[test]
+    fn matches_domain_with_leading_dot() {
+        assert!(cookie(".adventofcode.com", 0).matches_domain("adventofcode.com"));
+    }
+
+    #[test]
+    fn rejects_different_domain() {
+        assert!(!cookie("example.com", 0).matches_domain("adventofcode.com"));
+    }
+
+    #[test]
+    fn zero_expiry_never_expires() {
+        assert!(!cookie("adventofcode.com", 0).is_expired(u64::MAX));
+    }
+
+    #[test]
+    fn expired_when_past_deadline() {
+        assert!(cookie("adventofcode.com", 100).is_expired(200));
+    }
+
+    #[test]
+    fn not_expired_before_deadline() {
+        assert!(!cookie("adventofcode.com", 200).is_expired(100));
+    }
+}
@@ -1,4 +1,10 @@
+mod answer;
+mod client;
+mod cookies;
 mod star_info;
+mod throttle;
+
+pub use answer::{submit_answer, AnswerResult};
 
 use log::info;
 use reqwest::header::COOKIE;
@@ -19,21 +25,53 @@ impl DateInfo {
 
 pub type Result<T> = std::result::Result<T, FetchError>;
 
-#[derive(Debug, Clone)]
+#[derive(Debug)]
 pub enum FetchError {
-    Cause(String),
+    NotLiveYet { day: String, year: String },
+    InvalidSession,
+    ServiceUnavailable,
+    Http(reqwest::Error),
+    Io(std::io::Error),
+    Parse { value: String },
 }
 
 impl fmt::Display for FetchError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
-            FetchError::Cause(cause) => {
-                write!(f, "Error fetching Advent of Code input: {}", cause)
+            FetchError::NotLiveYet { day, year } => {
+                write!(f, "Puzzle for day {}-{} is not live yet", day, year)
             }
+            FetchError::InvalidSession => write!(f, "Session cookie is invalid"),
+            FetchError::ServiceUnavailable => write!(f, "Advent of Code is unavailable"),
+            FetchError::Http(e) => write!(f, "Error sending request: {}", e),
+            FetchError::Io(e) => write!(f, "I/O error: {}", e),
+            FetchError::Parse { value } => write!(f, "Unable to parse '{}'", value),
         }
     }
 }
 
+impl std::error::Error for FetchError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            FetchError::Http(e) => Some(e),
+            FetchError::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<reqwest::Error> for FetchError {
+    fn from(e: reqwest::Error) -> Self {
+        FetchError::Http(e)
+    }
+}
+
+impl From<std::io::Error> for FetchError {
+    fn from(e: std::io::Error) -> Self {
+        FetchError::Io(e)
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct AocInput {
     day: String,
@@ -41,7 +79,7 @@ pub struct AocInput {
     pub input: String,
 }
 
-const INPUT_DIR: &str = "inputs";
+pub(crate) const INPUT_DIR: &str = "inputs";
 
 impl AocInput {
     pub fn new(day: &str, year: &str, input: &str) -> AocInput {
@@ -58,32 +96,35 @@ impl AocInput {
             self.day, self.year, INPUT_DIR
         );
 
-        if !Path::new(INPUT_DIR).exists() && fs::create_dir(INPUT_DIR).is_err() {
-            return Err(FetchError::Cause(
-                "Unable to create input directory".to_string(),
-            ));
+        if !Path::new(INPUT_DIR).exists() {
+            fs::create_dir(INPUT_DIR)?;
         }
 
         let input_filename = self.get_input_filename(INPUT_DIR);
-
-        if fs::write(input_filename, &self.input).is_err() {
-            return Err(FetchError::Cause("Unable to write the file".to_string()));
-        }
+        fs::write(input_filename, &self.input)?;
 
         Ok(())
     }
 
-    pub fn split<T: FromStr>(&self) -> Vec<T> {
+    pub fn split<T: FromStr>(&self) -> Result<Vec<T>> {
         self.input
             .split_ascii_whitespace()
-            .map(|x| x.parse().ok().unwrap())
+            .map(|x| {
+                x.parse().map_err(|_| FetchError::Parse {
+                    value: x.to_string(),
+                })
+            })
             .collect()
     }
 
-    pub fn split_by<T: FromStr>(&self, delim: &str) -> Vec<T> {
+    pub fn split_by<T: FromStr>(&self, delim: &str) -> Result<Vec<T>> {
         self.input
             .split(delim)
-            .map(|x| x.parse().ok().unwrap())
+            .map(|x| {
+                x.parse().map_err(|_| FetchError::Parse {
+                    value: x.to_string(),
+                })
+            })
             .collect()
     }
 
@@ -101,74 +142,59 @@ impl ToString for AocInput {
 pub fn fetch_input(day: &str, year: &str, session: &str) -> Result<AocInput> {
     info!("Fetching input for day {}-{}", day, year);
 
+    throttle::wait_if_needed();
+
     let url = format!("https://adventofcode.com/{}/day/{}/input", year, day);
-    let input = reqwest::blocking::Client::new()
+    let response = client::build_client()
         .get(&url)
         .header(COOKIE, format!("session={}", session))
-        .send();
+        .send()?;
 
-    if input.is_err() {
-        return Err(FetchError::Cause("Error sending GET request".to_string()));
-    }
+    let response = match response.error_for_status() {
+        Ok(response) => response,
+        Err(e) => return Err(client::classify_status_error(day, year, e)),
+    };
 
-    let input = input.unwrap().text();
+    let input = response.text()?;
 
-    if input.is_err() {
-        return Err(FetchError::Cause(
-            "Error converting input to string".to_string(),
-        ));
+    if input.contains("log in") {
+        return Err(FetchError::InvalidSession);
     }
 
-    let input = input.unwrap();
-
-    if input.contains("Service Unavailable") {
-        return Err(FetchError::Cause("Advent of Code is dead".to_string()));
+    if input.contains("Internal Server Error") {
+        return Err(FetchError::ServiceUnavailable);
     }
 
+    // AoC sometimes serves its "not live yet"/throttling warnings with a 200,
+    // so the status-code classification above doesn't always catch them.
     if input.contains("Please don't repeatedly request") || input.contains("Not Found") {
-        return Err(FetchError::Cause(format!(
-            "Puzzle for day {} is not live yet",
-            day
-        )));
-    }
-
-    if input.contains("log in") {
-        return Err(FetchError::Cause("Session cookie is invalid".to_string()));
-    }
-
-    if input.contains("Internal Server Error") {
-        return Err(FetchError::Cause(
-            "Internal Server Error, invalid session cookie perhaps?".to_string(),
-        ));
+        return Err(FetchError::NotLiveYet {
+            day: day.to_string(),
+            year: year.to_string(),
+        });
     }
 
     Ok(AocInput::new(day, year, &input))
 }
 
-// todo
-// pub enum FetchMode
-// {
-//     Caching,
-//     Overriding,
-// }
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FetchMode {
+    Caching,
+    Overriding,
+}
 
-// pub fn fetch_and_save_input(day: &str, year: &str, mode: FetchMode) -> Result<String> {
-pub fn load_or_fetch_input(day: &str, year: &str) -> Result<AocInput> {
+pub fn load_or_fetch_input(day: &str, year: &str, mode: FetchMode) -> Result<AocInput> {
     let input = AocInput::new(day, year, "");
     let input_path = input.get_input_filename(INPUT_DIR);
 
-    if Path::new(&input_path).exists() {
+    if mode == FetchMode::Caching && Path::new(&input_path).exists() {
         info!("Loading input for day {}-{} from {}", day, year, input_path);
 
-        let raw_input = fs::read_to_string(input_path);
+        let raw_input = fs::read_to_string(input_path)?;
 
-        if raw_input.is_err() {
-            return Err(FetchError::Cause("Unable to read the file".to_string()));
-        }
-
-        Ok(AocInput::new(day, year, &raw_input.unwrap()))
+        Ok(AocInput::new(day, year, &raw_input))
     } else {
-        let session_cookie = get_session_cookie();
+        let session_cookie = get_session_cookie()?;
         let input = fetch_input(day, year, &session_cookie)?;
         input.save_to_file()?;
 
@@ -176,8 +202,42 @@ pub fn load_or_fetch_input(day: &str, year: &str) -> Result<AocInput> {
     }
 }
 
-fn get_session_cookie() -> String {
-    env::var("AOC_SESSION").expect("Expected a token in the environment")
+pub fn fetch_year(year: &str, session: &str, mode: FetchMode) -> Vec<Result<AocInput>> {
+    let mut results = Vec::new();
+
+    for day in 1..=25 {
+        let day = day.to_string();
+        let input = AocInput::new(&day, year, "");
+        let input_path = input.get_input_filename(INPUT_DIR);
+
+        if mode == FetchMode::Caching && Path::new(&input_path).exists() {
+            info!("Skipping day {}-{}, already on disk", day, year);
+            continue;
+        }
+
+        let fetched = fetch_input(&day, year, session);
+
+        if let Err(FetchError::NotLiveYet { .. }) = fetched {
+            info!("Day {}-{} is not live yet, stopping", day, year);
+            break;
+        }
+
+        if let Ok(input) = &fetched {
+            let _ = input.save_to_file();
+        }
+
+        results.push(fetched);
+    }
+
+    results
+}
+
+pub fn get_session_cookie() -> Result<String> {
+    if let Ok(session) = env::var("AOC_SESSION") {
+        return Ok(session);
+    }
+
+    cookies::load_session_from_cookies_file()
 }
 
 #[cfg(test)]
@@ -193,7 +253,7 @@ mod fetch_tests {
 
     #[test]
     fn valid_cookie_test() {
-        let session_cookie = get_session_cookie();
+        let session_cookie = get_session_cookie().expect("Expected a valid session");
         let input = fetch_input("1", "2018", &session_cookie);
 
         let expected = fs::read_to_string("test/2018-1-input.txt").expect("Error reading the file");
@@ -205,13 +265,13 @@ mod fetch_tests {
 
 #[cfg(test)]
 mod save_tests {
-    use crate::{fetch_input, get_session_cookie, load_or_fetch_input};
+    use crate::{fetch_input, get_session_cookie, load_or_fetch_input, FetchMode};
     use std::fs;
     use std::path::Path;
 
     #[test]
     fn save_input_test() {
-        let session_cookie = get_session_cookie();
+        let session_cookie = get_session_cookie().expect("Expected a valid session");
         let input = fetch_input("4", "2016", &session_cookie).unwrap();
 
         assert!(input.save_to_file().is_ok());
@@ -221,7 +281,7 @@ mod save_tests {
 
     #[test]
     fn fetch_and_save_test() {
-        let input = load_or_fetch_input("2", "2017");
+        let input = load_or_fetch_input("2", "2017", FetchMode::Caching);
         let expected = fs::read_to_string("test/2017-2-input.txt").expect("Error reading the file");
 
         assert!(input.is_ok());
@@ -231,7 +291,7 @@ mod save_tests {
 
     #[test]
     fn load_from_disk_test() {
-        let input = load_or_fetch_input("5", "2017");
+        let input = load_or_fetch_input("5", "2017", FetchMode::Caching);
         let filename = "inputs/2017-5.txt";
 
         assert!(input.is_ok());
@@ -239,12 +299,49 @@ mod save_tests {
 
         fs::write(filename, "data").expect("Unable to write file");
 
-        let input = load_or_fetch_input("5", "2017");
+        let input = load_or_fetch_input("5", "2017", FetchMode::Caching);
         assert!(input.is_ok());
         assert!(Path::new(filename).exists());
 
         assert_eq!("data", input.unwrap().input);
     }
+
+    #[test]
+    fn overriding_mode_refetches_test() {
+        let filename = "inputs/2017-6.txt";
+        fs::write(filename, "stale").expect("Unable to write file");
+
+        let input = load_or_fetch_input("6", "2017", FetchMode::Overriding);
+
+        assert!(input.is_ok());
+        assert_ne!("stale", input.unwrap().input);
+    }
+}
+
+#[cfg(test)]
+mod fetch_year_tests {
+    use crate::{fetch_year, get_session_cookie, FetchMode};
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    // Far enough out that the calendar can never catch up and unlock it.
+    fn far_future_year() -> String {
+        let now_secs = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+        let current_year = 1970 + now_secs / (365 * 24 * 60 * 60);
+
+        (current_year + 50).to_string()
+    }
+
+    #[test]
+    fn fetch_year_stops_at_unreleased_days_test() {
+        let session_cookie = get_session_cookie().expect("Expected a valid session");
+        let year = far_future_year();
+        let results = fetch_year(&year, &session_cookie, FetchMode::Overriding);
+
+        assert!(results.is_empty());
+    }
 }
 
 #[cfg(test)]
@@ -255,20 +352,30 @@ mod split_tests {
     fn split_as_int_vec() {
         let input = AocInput::new("1", "2020", "1 2 3 4 5");
 
-        assert_eq!(vec![1, 2, 3, 4, 5], input.split());
+        assert_eq!(vec![1, 2, 3, 4, 5], input.split().unwrap());
     }
 
     #[test]
     fn split_as_string_vec() {
         let input = AocInput::new("1", "2020", "1 2 3 4 5");
 
-        assert_eq!(vec!["1", "2", "3", "4", "5"], input.split::<String>());
+        assert_eq!(
+            vec!["1", "2", "3", "4", "5"],
+            input.split::<String>().unwrap()
+        );
     }
 
     #[test]
     fn split_with_delimiter() {
         let input = AocInput::new("1", "2020", "1,2,3,4,5");
 
-        assert_eq!(vec![1, 2, 3, 4, 5], input.split_by(","));
+        assert_eq!(vec![1, 2, 3, 4, 5], input.split_by(",").unwrap());
+    }
+
+    #[test]
+    fn split_with_unparseable_token_fails() {
+        let input = AocInput::new("1", "2020", "1 2 x 4 5");
+
+        assert!(input.split::<i32>().is_err());
     }
 }
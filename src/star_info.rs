@@ -1,4 +1,4 @@
-use crate::{DateInfo, FetchError};
+use crate::DateInfo;
 use crate::Result;
 use log::debug;
 use reqwest::header::COOKIE;
@@ -14,23 +14,28 @@ pub enum Stars
 pub fn get_stars(task: DateInfo, session: &str) -> Result<Stars> {
     debug!("Fetching star info for day {}-{}", task.day, task.year);
 
+    crate::throttle::wait_if_needed();
+
     let url = format!("https://adventofcode.com/{}/day/{}", task.year, task.day);
-    let input = reqwest::blocking::Client::new()
+    let response = crate::client::build_client()
         .get(&url)
         .header(COOKIE, format!("session={}", session))
-        .send();
+        .send()?;
 
-    if input.is_err() {
-        return Err(FetchError::Cause("Error sending GET request".to_string()));
-    }
+    let response = match response.error_for_status() {
+        Ok(response) => response,
+        Err(e) => return Err(crate::client::classify_status_error(&task.day, &task.year, e)),
+    };
 
-    let input = input.unwrap().text().unwrap();
+    let input = response.text()?;
 
+    // AoC sometimes serves its "not live yet"/throttling warnings with a 200,
+    // so the status-code classification above doesn't always catch them.
     if input.contains("Please don't repeatedly request") || input.contains("Not Found") {
-        return Err(FetchError::Cause(format!(
-            "Puzzle for day {} is not live yet",
-            task.day
-        )));
+        return Err(crate::FetchError::NotLiveYet {
+            day: task.day.clone(),
+            year: task.year.clone(),
+        });
     }
 
     if input.contains("The first half of this puzzle is complete!") {
@@ -52,7 +57,7 @@ mod get_stars_tests
 
     #[test]
     fn zero_stars_test() {
-        let session_cookie = get_session_cookie();
+        let session_cookie = get_session_cookie().expect("Expected a valid session");
         let result = get_stars(DateInfo::new("24", "2018"), &session_cookie);
 
         assert!(result.is_ok());
@@ -61,7 +66,7 @@ mod get_stars_tests
 
     #[test]
     fn one_star_test() {
-        let session_cookie = get_session_cookie();
+        let session_cookie = get_session_cookie().expect("Expected a valid session");
         let result = get_stars(DateInfo::new("22", "2018"), &session_cookie);
 
         assert!(result.is_ok());
@@ -70,7 +75,7 @@ mod get_stars_tests
 
     #[test]
     fn two_stars_test() {
-        let session_cookie = get_session_cookie();
+        let session_cookie = get_session_cookie().expect("Expected a valid session");
         let result = get_stars(DateInfo::new("1", "2018"), &session_cookie);
 
         assert!(result.is_ok());
@@ -79,7 +84,7 @@ mod get_stars_tests
 
     #[test]
     fn dead_test() {
-        let session_cookie = get_session_cookie();
+        let session_cookie = get_session_cookie().expect("Expected a valid session");
         let result = get_stars(DateInfo::new("30", "2018"), &session_cookie);
 
         assert!(result.is_err());
@@ -0,0 +1,91 @@
+use log::debug;
+use std::env;
+use std::fs;
+use std::path::Path;
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+const THROTTLE_FILE: &str = "inputs/.last_request";
+const DEFAULT_MIN_REQUEST_INTERVAL_SECS: u64 = 3;
+const MIN_REQUEST_INTERVAL_ENV_VAR: &str = "AOC_FETCH_THROTTLE_SECS";
+
+pub fn wait_if_needed() {
+    let min_interval = min_request_interval();
+
+    if let Some(elapsed) = elapsed_since_last_request() {
+        if elapsed < min_interval {
+            let remaining = min_interval - elapsed;
+            debug!("Throttling request, sleeping for {:?}", remaining);
+            thread::sleep(remaining);
+        }
+    }
+
+    record_request();
+}
+
+fn min_request_interval() -> Duration {
+    parse_min_request_interval(env::var(MIN_REQUEST_INTERVAL_ENV_VAR).ok())
+}
+
+fn parse_min_request_interval(raw: Option<String>) -> Duration {
+    let secs = raw
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(DEFAULT_MIN_REQUEST_INTERVAL_SECS);
+
+    Duration::from_secs(secs)
+}
+
+fn elapsed_since_last_request() -> Option<Duration> {
+    let raw = fs::read_to_string(THROTTLE_FILE).ok()?;
+    let last_request = raw.trim().parse::<u64>().ok()?;
+    let now = now_as_secs();
+
+    Some(Duration::from_secs(now.saturating_sub(last_request)))
+}
+
+fn record_request() {
+    if let Some(parent) = Path::new(THROTTLE_FILE).parent() {
+        if !parent.as_os_str().is_empty() && !parent.exists() {
+            let _ = fs::create_dir(parent);
+        }
+    }
+
+    let _ = fs::write(THROTTLE_FILE, now_as_secs().to_string());
+}
+
+fn now_as_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs()
+}
+
+#[cfg(test)]
+mod min_request_interval_tests {
+    use super::{parse_min_request_interval, DEFAULT_MIN_REQUEST_INTERVAL_SECS};
+    use std::time::Duration;
+
+    #[test]
+    fn defaults_when_value_missing() {
+        assert_eq!(
+            Duration::from_secs(DEFAULT_MIN_REQUEST_INTERVAL_SECS),
+            parse_min_request_interval(None)
+        );
+    }
+
+    #[test]
+    fn uses_value_when_present() {
+        assert_eq!(
+            Duration::from_secs(10),
+            parse_min_request_interval(Some("10".to_string()))
+        );
+    }
+
+    #[test]
+    fn defaults_on_unparseable_value() {
+        assert_eq!(
+            Duration::from_secs(DEFAULT_MIN_REQUEST_INTERVAL_SECS),
+            parse_min_request_interval(Some("not-a-number".to_string()))
+        );
+    }
+}